@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use bitstream_io::{BigEndian, BitRead, BitReader, BitWrite, BitWriter};
+use pyo3::prelude::*;
+
+use crate::error::Base64x32Error;
+use crate::{B64_CONVERSOR, MAIN_CONVERSOR};
+
+const MAIN_SYMBOL_COUNT: usize = 2048;
+const TAIL_SYMBOL_COUNT: usize = 64;
+
+/// A validated pair of symbol tables - 2048 entries for 11-bit groups and 64
+/// entries for the 6-bit tail - used to encode and decode base64x32 streams.
+///
+/// Construct one with [`Codec::new`] to plug in a custom Minecraft glyph set,
+/// a URL-safe variant, or any other mapping, without forking the crate. Use
+/// `Codec::default()` to get the crate's built-in Minecraft alphabet, which
+/// is what `encode_rust`/`decode_rust` use under the hood.
+pub struct Codec {
+    main_encode: Vec<String>,
+    main_decode: HashMap<String, u16>,
+    main_symbol_len: usize,
+    tail_encode: Vec<String>,
+    tail_decode: HashMap<String, u8>,
+    tail_symbol_len: usize,
+}
+
+impl Codec {
+    /// Builds a codec from caller-supplied symbol lists. `main_symbols` must
+    /// contain exactly 2048 entries and `tail_symbols` exactly 64. Symbols
+    /// must be a consistent character length within each list (the native
+    /// alphabet uses 2-char main symbols and a 1-char tail, but main and
+    /// tail widths are validated independently and need not match each
+    /// other), with no duplicate symbols within either list. The tail width
+    /// must not be a multiple of the main width, so that `decode` can tell
+    /// from the character count whether a tail symbol is present.
+    pub fn new(main_symbols: Vec<String>, tail_symbols: Vec<String>) -> Result<Self, Base64x32Error> {
+        if main_symbols.len() != MAIN_SYMBOL_COUNT {
+            return Err(Base64x32Error::InvalidAlphabet(format!(
+                "main alphabet must contain exactly {} symbols, got {}",
+                MAIN_SYMBOL_COUNT, main_symbols.len()
+            )));
+        }
+        if tail_symbols.len() != TAIL_SYMBOL_COUNT {
+            return Err(Base64x32Error::InvalidAlphabet(format!(
+                "tail alphabet must contain exactly {} symbols, got {}",
+                TAIL_SYMBOL_COUNT, tail_symbols.len()
+            )));
+        }
+
+        let main_symbol_len = main_symbols[0].chars().count();
+        if main_symbols.iter().any(|s| s.chars().count() != main_symbol_len) {
+            return Err(Base64x32Error::InvalidAlphabet(format!(
+                "all main symbols must be {} characters long", main_symbol_len
+            )));
+        }
+
+        let tail_symbol_len = tail_symbols[0].chars().count();
+        if tail_symbols.iter().any(|s| s.chars().count() != tail_symbol_len) {
+            return Err(Base64x32Error::InvalidAlphabet(format!(
+                "all tail symbols must be {} characters long", tail_symbol_len
+            )));
+        }
+
+        let mut main_decode = HashMap::with_capacity(main_symbols.len());
+        for (i, symbol) in main_symbols.iter().enumerate() {
+            if main_decode.insert(symbol.clone(), i as u16).is_some() {
+                return Err(Base64x32Error::InvalidAlphabet(format!(
+                    "duplicate symbol {:?} in main alphabet", symbol
+                )));
+            }
+        }
+
+        let mut tail_decode = HashMap::with_capacity(tail_symbols.len());
+        for (i, symbol) in tail_symbols.iter().enumerate() {
+            if tail_decode.insert(symbol.clone(), i as u8).is_some() {
+                return Err(Base64x32Error::InvalidAlphabet(format!(
+                    "duplicate symbol {:?} in tail alphabet", symbol
+                )));
+            }
+        }
+
+        if tail_symbol_len % main_symbol_len == 0 {
+            return Err(Base64x32Error::InvalidAlphabet(format!(
+                "tail symbol width {} must not be a multiple of main symbol width {}",
+                tail_symbol_len, main_symbol_len
+            )));
+        }
+
+        Ok(Codec {
+            main_encode: main_symbols,
+            main_decode,
+            main_symbol_len,
+            tail_encode: tail_symbols,
+            tail_decode,
+            tail_symbol_len,
+        })
+    }
+
+    fn encode_main(&self, input: u16) -> &str {
+        &self.main_encode[input as usize]
+    }
+
+    fn decode_main(&self, input: String, position: usize) -> Result<u16, Base64x32Error> {
+        self.main_decode
+            .get(&input)
+            .copied()
+            .ok_or(Base64x32Error::InvalidSymbol { position, text: input })
+    }
+
+    fn encode_tail(&self, input: u8) -> &str {
+        &self.tail_encode[input as usize]
+    }
+
+    fn decode_tail(&self, input: String, position: usize) -> Result<u8, Base64x32Error> {
+        self.tail_decode
+            .get(&input)
+            .copied()
+            .ok_or(Base64x32Error::InvalidSymbol { position, text: input })
+    }
+
+    /// Encodes raw bytes into base64x32 text using this codec's alphabet.
+    pub fn encode(&self, input: &[u8]) -> String {
+        let mut output = String::new();
+
+        let mut reader = BitReader::endian(input, BigEndian);
+        let total_bits = input.len() * 8;
+        let complete_chunks = total_bits / 11;
+
+        for _ in 0..complete_chunks {
+            let val = reader.read::<11, u16>().unwrap();
+            output.push_str(self.encode_main(val));
+        }
+
+        let bits_left = total_bits % 11;
+        if bits_left == 0 {
+            return output;
+        }
+        let extra_bits = reader.read_var::<u16>(bits_left as u32).unwrap();
+
+        if bits_left <= 6 {
+            let end_data: u8 = (extra_bits as u8) << (6 - bits_left);
+            output.push_str(self.encode_tail(end_data));
+        } else {
+            let end_data = extra_bits << (11 - bits_left);
+            output.push_str(self.encode_main(end_data));
+        }
+
+        output
+    }
+
+    /// Decodes base64x32 text into raw bytes using this codec's alphabet.
+    ///
+    /// Steps through `input` by the main symbol width rather than assuming
+    /// 2 characters, and determines whether a trailing tail symbol (read by
+    /// the tail width rather than assuming 1 character) is present by
+    /// checking which split of the character count is consistent with the
+    /// widths - mirroring `encode`'s "one optional tail symbol at the end"
+    /// shape.
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>, Base64x32Error> {
+        let inputs_chars: Vec<char> = input.chars().collect();
+        let total = inputs_chars.len();
+        let main_len = self.main_symbol_len;
+        let tail_len = self.tail_symbol_len;
+
+        let main_chars_len = if total % main_len == 0 {
+            total
+        } else if total >= tail_len && (total - tail_len) % main_len == 0 {
+            total - tail_len
+        } else {
+            return Err(Base64x32Error::UnexpectedEof);
+        };
+        let has_tail = main_chars_len != total;
+
+        let mut raw_decoded: Vec<u16> = Vec::with_capacity(main_chars_len / main_len.max(1));
+        let mut pos = 0;
+        while pos < main_chars_len {
+            let chunk: String = inputs_chars[pos..pos + main_len].iter().collect();
+            raw_decoded.push(self.decode_main(chunk, pos)?);
+            pos += main_len;
+        }
+
+        let mut output: Vec<u8> = Vec::new();
+        let mut writer = BitWriter::endian(&mut output, BigEndian);
+
+        for &numero in &raw_decoded {
+            writer
+                .write_var(11, numero)
+                .map_err(|e| Base64x32Error::BitstreamError(e.to_string()))?;
+        }
+
+        if has_tail {
+            let tail_chunk: String = inputs_chars[main_chars_len..].iter().collect();
+            let last_val = self.decode_tail(tail_chunk, main_chars_len)?;
+            writer
+                .write_var(6, last_val)
+                .map_err(|e| Base64x32Error::BitstreamError(e.to_string()))?;
+        }
+
+        Ok(output)
+    }
+}
+
+impl Default for Codec {
+    /// The crate's built-in Minecraft glyph alphabet, identical to the
+    /// global tables `encode_rust`/`decode_rust` use.
+    fn default() -> Self {
+        Codec {
+            main_symbol_len: MAIN_CONVERSOR.0[0].chars().count(),
+            main_encode: MAIN_CONVERSOR.0.to_vec(),
+            main_decode: MAIN_CONVERSOR.1.clone(),
+            tail_symbol_len: B64_CONVERSOR.0[0].chars().count(),
+            tail_encode: B64_CONVERSOR.0.to_vec(),
+            tail_decode: B64_CONVERSOR.1.clone(),
+        }
+    }
+}
+
+/// Python-visible wrapper exposing [`Codec`] construction and use so callers
+/// can register their own symbol tables at runtime instead of forking the
+/// crate.
+#[pyclass(name = "Codec")]
+pub struct PyCodec {
+    inner: Codec,
+}
+
+#[pymethods]
+impl PyCodec {
+    #[new]
+    fn new(main_symbols: Vec<String>, tail_symbols: Vec<String>) -> PyResult<Self> {
+        Ok(PyCodec { inner: Codec::new(main_symbols, tail_symbols)? })
+    }
+
+    #[staticmethod]
+    fn default_codec() -> Self {
+        PyCodec { inner: Codec::default() }
+    }
+
+    fn encode(&self, input: Vec<u8>) -> String {
+        self.inner.encode(&input)
+    }
+
+    fn decode(&self, input: &str) -> PyResult<Vec<u8>> {
+        Ok(self.inner.decode(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn native_codec() -> Codec {
+        Codec::new(MAIN_CONVERSOR.0.to_vec(), B64_CONVERSOR.0.to_vec())
+            .expect("the crate's own alphabet must pass its own validation")
+    }
+
+    #[test]
+    fn new_accepts_the_native_alphabet() {
+        native_codec();
+    }
+
+    #[test]
+    fn round_trip_over_all_residues() {
+        let codec = native_codec();
+        for residue in 0..=7u8 {
+            let input: Vec<u8> = (0..11 + residue).collect();
+            let encoded = codec.encode(&input);
+            let decoded = codec.decode(&encoded).unwrap();
+            assert_eq!(decoded, input, "round-trip mismatch for {} trailing bytes", residue);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::PyErr;
+
+/// Errors produced while decoding base64x32 text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base64x32Error {
+    /// A main or tail symbol did not match any entry in its alphabet.
+    InvalidSymbol { position: usize, text: String },
+    /// The input ended in the middle of a symbol or bitstream that expected
+    /// more data.
+    UnexpectedEof,
+    /// The underlying bit reader/writer failed to pack or unpack bits.
+    BitstreamError(String),
+    /// A caller-supplied symbol table failed construction-time validation.
+    InvalidAlphabet(String),
+}
+
+impl fmt::Display for Base64x32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base64x32Error::InvalidSymbol { position, text } => {
+                write!(f, "invalid symbol {:?} at character offset {}", text, position)
+            }
+            Base64x32Error::UnexpectedEof => write!(f, "unexpected end of input"),
+            Base64x32Error::BitstreamError(msg) => write!(f, "bitstream error: {}", msg),
+            Base64x32Error::InvalidAlphabet(msg) => write!(f, "invalid alphabet: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Base64x32Error {}
+
+impl From<Base64x32Error> for PyErr {
+    fn from(err: Base64x32Error) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
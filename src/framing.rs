@@ -0,0 +1,96 @@
+use pyo3::prelude::*;
+
+use crate::error::Base64x32Error;
+use crate::{decode_base, decode_chars_scalar, encode_base, encode_bytes_scalar};
+
+/// `encode_rust`/`decode_rust` pad the final partial group with zero bits and
+/// rebuild the byte count from the symbol count alone. This framing prepends
+/// a fixed-width length prefix (3 main-alphabet symbols, enough to hold a
+/// `u32` byte count) so the original length is recorded explicitly in the
+/// text and the decoded output is cut to exactly that length.
+const LEN_PREFIX_SYMBOLS: usize = 3;
+const LEN_PREFIX_CHARS: usize = LEN_PREFIX_SYMBOLS * 2;
+
+fn encode_length_prefix(len: u32) -> String {
+    let mut output = String::new();
+    let widened = len as u64;
+
+    for chunk in 0..LEN_PREFIX_SYMBOLS {
+        let shift = (LEN_PREFIX_SYMBOLS - 1 - chunk) * 11;
+        let symbol = ((widened >> shift) & 0x7FF) as u16;
+        output.push_str(encode_base(symbol));
+    }
+
+    output
+}
+
+fn decode_length_prefix(chars: &[char]) -> Result<(u32, usize), Base64x32Error> {
+    if chars.len() < LEN_PREFIX_CHARS {
+        return Err(Base64x32Error::UnexpectedEof);
+    }
+
+    let mut value: u64 = 0;
+    for i in 0..LEN_PREFIX_SYMBOLS {
+        let symbol: String = chars[i * 2..i * 2 + 2].iter().collect();
+        let val = decode_base(symbol, i * 2)?;
+        value = (value << 11) | val as u64;
+    }
+
+    Ok((value as u32, LEN_PREFIX_CHARS))
+}
+
+/// Encodes `input` with a canonical length prefix so [`decode_exact`] can
+/// truncate its output to the original byte count.
+pub fn encode_exact(input: &[u8]) -> String {
+    let mut output = encode_length_prefix(input.len() as u32);
+    output.push_str(&encode_bytes_scalar(input));
+    output
+}
+
+/// Decodes text produced by [`encode_exact`], truncating the result to the
+/// exact original byte count recorded in the length prefix.
+pub fn decode_exact(input: &str) -> Result<Vec<u8>, Base64x32Error> {
+    let chars: Vec<char> = input.chars().collect();
+    let (original_len, consumed) = decode_length_prefix(&chars)?;
+
+    let mut decoded = decode_chars_scalar(chars[consumed..].to_vec())?;
+    decoded.truncate(original_len as usize);
+    Ok(decoded)
+}
+
+#[pyfunction]
+pub(crate) fn encode_exact_rust(input: Vec<u8>) -> String {
+    encode_exact(&input)
+}
+
+#[pyfunction]
+pub(crate) fn decode_exact_rust(input: &str) -> PyResult<Vec<u8>> {
+    Ok(decode_exact(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_is_length_exact_for_all_residues() {
+        // Bit residue is `len * 8 % 11`; since gcd(8, 11) == 1, every run of
+        // 11 consecutive lengths cycles through all 11 residue classes.
+        for len in 11..=21u32 {
+            let input: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let encoded = encode_exact(&input);
+            let decoded = decode_exact(&encoded).unwrap();
+            assert_eq!(
+                decoded, input,
+                "round-trip mismatch for length {} (bit residue {})", len, (len * 8) % 11
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip_handles_empty_input() {
+        let encoded = encode_exact(&[]);
+        let decoded = decode_exact(&encoded).unwrap();
+        assert_eq!(decoded, Vec::<u8>::new());
+    }
+}
@@ -4,6 +4,17 @@ use lazy_static::lazy_static;
 use bitstream_io::{BitReader, BigEndian, BitRead, BitWriter, BitWrite};
 use pyo3::prelude::*;
 
+mod codec;
+mod error;
+mod framing;
+#[cfg(feature = "simd")]
+mod simd;
+mod stream;
+pub use codec::Codec;
+pub use error::Base64x32Error;
+pub use framing::{decode_exact, encode_exact};
+pub use stream::{Base64x32Reader, Base64x32Writer};
+
 const BASE_JSON: &str = include_str!("../mcbase64x32/utils/baseList.json");
 const BASE64_JSON: &str = include_str!("../mcbase64x32/utils/thinBase64.json");
 
@@ -21,7 +32,7 @@ struct Base64List {
 }
 
 lazy_static! {
-    static ref MAIN_CONVERSOR: ([String; 2048], HashMap<String, u16>) = {
+    pub(crate) static ref MAIN_CONVERSOR: ([String; 2048], HashMap<String, u16>) = {
         let base: BaseList = serde_json::from_str(BASE_JSON).unwrap();
 
         let arr: [String; 2048] = base.encode
@@ -31,7 +42,7 @@ lazy_static! {
         (arr, base.decode)
     };
 
-    static ref B64_CONVERSOR: ([String; 64], HashMap<String, u8>) = {
+    pub(crate) static ref B64_CONVERSOR: ([String; 64], HashMap<String, u8>) = {
         let base: Base64List = serde_json::from_str(BASE64_JSON).unwrap();
         let arr: [String; 64] = base.encode
             .try_into()
@@ -40,27 +51,44 @@ lazy_static! {
     };
 }
 
-fn encode_base(input: u16) -> &'static str {
+pub(crate) fn encode_base(input: u16) -> &'static str {
     &MAIN_CONVERSOR.0[input as usize]
 }
 
-fn decode_base(input: String) -> u16 {
-    MAIN_CONVERSOR.1[&input]
+pub(crate) fn decode_base(input: String, position: usize) -> Result<u16, Base64x32Error> {
+    MAIN_CONVERSOR.1
+        .get(&input)
+        .copied()
+        .ok_or(Base64x32Error::InvalidSymbol { position, text: input })
 }
 
-fn encode_b64(input: u8) -> &'static str {
+pub(crate) fn encode_b64(input: u8) -> &'static str {
     &B64_CONVERSOR.0[input as usize]
 }
 
-fn decode_b64(input: String) -> u8 {
-    B64_CONVERSOR.1[&input]
+pub(crate) fn decode_b64(input: String, position: usize) -> Result<u8, Base64x32Error> {
+    B64_CONVERSOR.1
+        .get(&input)
+        .copied()
+        .ok_or(Base64x32Error::InvalidSymbol { position, text: input })
 }
 
 #[pyfunction]
 fn encode_rust(input: Vec<u8>) -> String {
+    #[cfg(feature = "simd")]
+    {
+        simd::encode_bulk(&input)
+    }
+    #[cfg(not(feature = "simd"))]
+    {
+        encode_bytes_scalar(&input)
+    }
+}
+
+pub(crate) fn encode_bytes_scalar(input: &[u8]) -> String {
     let mut output = String::new();
 
-    let mut reader = BitReader::endian(&input[..], BigEndian);
+    let mut reader = BitReader::endian(input, BigEndian);
     let total_bits = input.len() * 8;
     let complete_chunks = total_bits / 11;
 
@@ -91,12 +119,35 @@ fn encode_rust(input: Vec<u8>) -> String {
 }
 
 #[pyfunction]
-fn decode_rust(input: &str) -> Vec<u8> {
+fn decode_rust(input: &str) -> PyResult<Vec<u8>> {
+    #[cfg(feature = "simd")]
+    {
+        Ok(simd::decode_bulk(input)?)
+    }
+    #[cfg(not(feature = "simd"))]
+    {
+        Ok(decode_chars_scalar(input.chars().collect())?)
+    }
+}
+
+/// Like `decode_rust`, but tolerates ASCII whitespace (spaces, tabs, CR/LF)
+/// inserted into the encoded text by email clients, line wrapping, or
+/// copy-pasting, skipping it before pairing characters into symbols.
+///
+/// Note: an `Err(Base64x32Error::InvalidSymbol { position, .. })` reports an
+/// offset into the whitespace-stripped character stream, not into `input` -
+/// the stripped characters aren't counted, so it won't generally line up
+/// with the corresponding character in the original string.
+#[pyfunction]
+fn decode_lenient(input: &str) -> PyResult<Vec<u8>> {
+    Ok(decode_chars_scalar(input.chars().filter(|c| !c.is_ascii_whitespace()).collect())?)
+}
+
+pub(crate) fn decode_chars_scalar(inputs_chars: Vec<char>) -> Result<Vec<u8>, Base64x32Error> {
     let mut raw_decoded: Vec<u16> = vec![];
-    let inputs_chars: Vec<char> = input.chars().collect();
     for i in (1..inputs_chars.len()).step_by(2) {
         let chunk = inputs_chars[i-1..i+1].iter().collect::<String>();
-        let val = decode_base(chunk);
+        let val = decode_base(chunk, i - 1)?;
         raw_decoded.push(val);
     }
 
@@ -104,19 +155,18 @@ fn decode_rust(input: &str) -> Vec<u8> {
     let mut writer = BitWriter::endian(&mut output, BigEndian);
 
     for &numero in &raw_decoded {
-        writer.write_var(11, numero).unwrap();
+        writer.write_var(11, numero)
+            .map_err(|e| Base64x32Error::BitstreamError(e.to_string()))?;
     }
 
     if inputs_chars.len() % 2 == 1 {
-        let last_val = decode_b64(inputs_chars[inputs_chars.len()-1].to_string());
-        writer.write_var(6, last_val).unwrap();
+        let last_pos = inputs_chars.len() - 1;
+        let last_val = decode_b64(inputs_chars[last_pos].to_string(), last_pos)?;
+        writer.write_var(6, last_val)
+            .map_err(|e| Base64x32Error::BitstreamError(e.to_string()))?;
     }
 
-    //let padding_bits = ((input.len()/2)*11)%8;
-
-    //println!("{:?}", output);
-
-    output
+    Ok(output)
 }
 
 /// A Python module for encoding and decoding using custom base64x32 algorithm
@@ -124,5 +174,9 @@ fn decode_rust(input: &str) -> Vec<u8> {
 fn mcbase64x32(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(encode_rust, m)?)?;
     m.add_function(wrap_pyfunction!(decode_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_lenient, m)?)?;
+    m.add_function(wrap_pyfunction!(framing::encode_exact_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(framing::decode_exact_rust, m)?)?;
+    m.add_class::<codec::PyCodec>()?;
     Ok(())
 }
\ No newline at end of file
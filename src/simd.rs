@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use bitstream_io::{BigEndian, BitWrite, BitWriter};
+use lazy_static::lazy_static;
+
+use crate::error::Base64x32Error;
+use crate::{decode_b64, decode_chars_scalar, encode_bytes_scalar, B64_CONVERSOR, MAIN_CONVERSOR};
+
+/// Faster block-processing paths for large buffers, gated behind the `simd`
+/// feature. Despite the feature name, these are scalar optimizations (fewer
+/// allocations, direct `memcpy`s) rather than actual SIMD intrinsics.
+///
+/// Below this many input bytes the scalar loop is already fast enough that
+/// the bulk path's setup cost isn't worth paying.
+const BULK_ENCODE_THRESHOLD: usize = 4096;
+const BULK_DECODE_THRESHOLD: usize = 4096;
+
+/// A symbol table flattened into one contiguous byte buffer plus
+/// (start, len) offsets, so a symbol's bytes can be `memcpy`'d straight
+/// into an output buffer instead of going through `String::push_str`.
+struct FlatTable {
+    bytes: Vec<u8>,
+    offsets: Vec<(u32, u32)>,
+}
+
+impl FlatTable {
+    fn build(symbols: &[String]) -> Self {
+        let mut bytes = Vec::new();
+        let mut offsets = Vec::with_capacity(symbols.len());
+        for s in symbols {
+            let start = bytes.len() as u32;
+            bytes.extend_from_slice(s.as_bytes());
+            offsets.push((start, s.len() as u32));
+        }
+        FlatTable { bytes, offsets }
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> &[u8] {
+        let (start, len) = self.offsets[index];
+        &self.bytes[start as usize..(start + len) as usize]
+    }
+}
+
+lazy_static! {
+    static ref MAIN_FLAT: FlatTable = FlatTable::build(&MAIN_CONVERSOR.0);
+    static ref TAIL_FLAT: FlatTable = FlatTable::build(&B64_CONVERSOR.0);
+
+    // Keyed by the symbol's two characters directly, so decoding a chunk
+    // doesn't need to allocate a `String` just to probe the map.
+    static ref MAIN_PAIR_DECODE: HashMap<(char, char), u16> = {
+        let mut map = HashMap::with_capacity(MAIN_CONVERSOR.0.len());
+        for (i, symbol) in MAIN_CONVERSOR.0.iter().enumerate() {
+            let mut chars = symbol.chars();
+            if let (Some(a), Some(b), None) = (chars.next(), chars.next(), chars.next()) {
+                map.insert((a, b), i as u16);
+            }
+        }
+        map
+    };
+}
+
+/// Encodes `input` via a block-wise fast path: three raw bytes (24 bits =
+/// two 11-bit symbols + 2 leftover bits carried into the next block) are
+/// gathered at a time and their symbol bytes copied directly into a
+/// pre-sized output buffer, instead of reading one 11-bit value at a time
+/// through a `BitReader`. Falls back to the scalar implementation for
+/// inputs too small to amortize the setup cost.
+pub(crate) fn encode_bulk(input: &[u8]) -> String {
+    if input.len() < BULK_ENCODE_THRESHOLD {
+        return encode_bytes_scalar(input);
+    }
+
+    let mut out: Vec<u8> = Vec::with_capacity(input.len() * 11 / 8 + 4);
+    // `carry_bits` can reach 10, so the window (carry_bits + 24 bits, up to
+    // 34) does not fit in a `u32` - use `u64` throughout, matching the tail
+    // fold below.
+    let mut carry: u64 = 0;
+    let mut carry_bits: u32 = 0;
+
+    let full_blocks = input.len() / 3;
+    for block in 0..full_blocks {
+        let i = block * 3;
+        let chunk = ((input[i] as u64) << 16) | ((input[i + 1] as u64) << 8) | input[i + 2] as u64;
+        let window = (carry << 24) | chunk;
+        let mut remaining = carry_bits + 24;
+
+        while remaining >= 11 {
+            remaining -= 11;
+            let symbol = ((window >> remaining) & 0x7FF) as usize;
+            out.extend_from_slice(MAIN_FLAT.get(symbol));
+        }
+
+        carry = window & ((1 << remaining) - 1);
+        carry_bits = remaining;
+    }
+
+    // Fold the final carry bits and any trailing whole bytes (0-2 of them)
+    // into a single tail, identical in shape to `encode_bytes_scalar`'s.
+    let mut bit_buf: u64 = carry;
+    let mut bit_count = carry_bits;
+    for &b in &input[full_blocks * 3..] {
+        bit_buf = (bit_buf << 8) | b as u64;
+        bit_count += 8;
+    }
+
+    while bit_count >= 11 {
+        bit_count -= 11;
+        let symbol = ((bit_buf >> bit_count) & 0x7FF) as usize;
+        out.extend_from_slice(MAIN_FLAT.get(symbol));
+    }
+
+    if bit_count > 0 {
+        let extra = bit_buf & ((1 << bit_count) - 1);
+        if bit_count <= 6 {
+            let end_data = (extra as u8) << (6 - bit_count);
+            out.extend_from_slice(TAIL_FLAT.get(end_data as usize));
+        } else {
+            let end_data = (extra as u16) << (11 - bit_count);
+            out.extend_from_slice(MAIN_FLAT.get(end_data as usize));
+        }
+    }
+
+    String::from_utf8(out).expect("alphabet symbols are valid utf-8")
+}
+
+/// Decodes `input` via a `(char, char)`-keyed lookup instead of allocating a
+/// `String` per two-character symbol, avoiding the per-chunk heap
+/// allocation the scalar `HashMap<String, _>` probe requires. Falls back to
+/// the scalar implementation for inputs too small to amortize the setup
+/// cost.
+///
+/// Note this is a scalar block-processing path, not actual SIMD: with
+/// symbols drawn from arbitrary Unicode code points, a dense array indexed
+/// directly by a symbol's two characters isn't practical (the index space
+/// is far too large to size an array by), so this still probes a hash map -
+/// just one keyed by `(char, char)` instead of an allocated `String`.
+pub(crate) fn decode_bulk(input: &str) -> Result<Vec<u8>, Base64x32Error> {
+    if input.len() < BULK_DECODE_THRESHOLD {
+        return decode_chars_scalar(input.chars().collect());
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let mut raw_decoded: Vec<u16> = Vec::with_capacity(chars.len() / 2);
+
+    let mut i = 0;
+    while i + 1 < chars.len() {
+        let pair = (chars[i], chars[i + 1]);
+        let val = MAIN_PAIR_DECODE
+            .get(&pair)
+            .copied()
+            .ok_or_else(|| Base64x32Error::InvalidSymbol {
+                position: i,
+                text: [pair.0, pair.1].iter().collect(),
+            })?;
+        raw_decoded.push(val);
+        i += 2;
+    }
+
+    let mut output: Vec<u8> = Vec::new();
+    let mut writer = BitWriter::endian(&mut output, BigEndian);
+
+    for &numero in &raw_decoded {
+        writer
+            .write_var(11, numero)
+            .map_err(|e| Base64x32Error::BitstreamError(e.to_string()))?;
+    }
+
+    if chars.len() % 2 == 1 {
+        let last_pos = chars.len() - 1;
+        let last_val = decode_b64(chars[last_pos].to_string(), last_pos)?;
+        writer
+            .write_var(6, last_val)
+            .map_err(|e| Base64x32Error::BitstreamError(e.to_string()))?;
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn large_input() -> Vec<u8> {
+        (0..BULK_ENCODE_THRESHOLD as u32 + 37).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn bulk_encode_matches_scalar_for_large_input() {
+        let input = large_input();
+        assert_eq!(encode_bulk(&input), encode_bytes_scalar(&input));
+    }
+
+    #[test]
+    fn bulk_round_trip_for_large_input() {
+        let input = large_input();
+        let encoded = encode_bulk(&input);
+        assert_eq!(decode_bulk(&encoded).unwrap(), input);
+    }
+}
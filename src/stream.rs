@@ -0,0 +1,219 @@
+use std::io::{self, Read, Write};
+
+use crate::{decode_b64, decode_base, encode_b64, encode_base, Base64x32Error};
+
+fn to_io_error(err: Base64x32Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Incrementally encodes bytes written to it into base64x32 text written to an
+/// inner `Write`, without ever materializing the whole input or output in memory.
+///
+/// Bytes handed to `write` are folded into a bit buffer; every time 11 bits have
+/// accumulated, a symbol is emitted through [`encode_base`] and the buffer is
+/// drained. Call [`finish`](Base64x32Writer::finish) once all input has been
+/// written to flush the trailing 0-10 residual bits and recover the inner writer.
+pub struct Base64x32Writer<W: Write> {
+    inner: W,
+    bit_buf: u32,
+    bit_count: u32,
+}
+
+impl<W: Write> Base64x32Writer<W> {
+    pub fn new(inner: W) -> Self {
+        Base64x32Writer { inner, bit_buf: 0, bit_count: 0 }
+    }
+
+    fn flush_symbols(&mut self) -> io::Result<()> {
+        while self.bit_count >= 11 {
+            let shift = self.bit_count - 11;
+            let val = ((self.bit_buf >> shift) & 0x7FF) as u16;
+            self.inner.write_all(encode_base(val).as_bytes())?;
+            self.bit_count -= 11;
+            self.bit_buf &= (1u32 << self.bit_count) - 1;
+        }
+        Ok(())
+    }
+
+    /// Flushes the residual bits (0-10 of them) as a final 6-bit or 11-bit
+    /// symbol, the same tail convention `encode_rust` uses, and returns the
+    /// inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush_symbols()?;
+
+        if self.bit_count > 0 {
+            let bits_left = self.bit_count;
+            let extra = self.bit_buf & ((1u32 << bits_left) - 1);
+
+            if bits_left <= 6 {
+                let end_data = (extra as u8) << (6 - bits_left);
+                self.inner.write_all(encode_b64(end_data).as_bytes())?;
+            } else {
+                let end_data = (extra as u16) << (11 - bits_left);
+                self.inner.write_all(encode_base(end_data).as_bytes())?;
+            }
+        }
+
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for Base64x32Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &byte in buf {
+            self.bit_buf = (self.bit_buf << 8) | byte as u32;
+            self.bit_count += 8;
+            self.flush_symbols()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Incrementally decodes base64x32 text pulled from an inner `Read`, yielding
+/// raw bytes through the standard `Read` interface without materializing the
+/// full decoded output.
+///
+/// Encoded text is pulled from the inner reader in chunks of up to 512 bytes,
+/// its characters are decoded two at a time through [`decode_base`], and the
+/// resulting 11-bit values are folded into a
+/// bit buffer that `read` drains a byte at a time. A trailing unpaired
+/// character is decoded through [`decode_b64`] once the inner reader is
+/// exhausted.
+pub struct Base64x32Reader<R: Read> {
+    inner: R,
+    pending_bytes: Vec<u8>,
+    pending_chars: Vec<char>,
+    bit_buf: u32,
+    bit_count: u32,
+    chars_consumed: usize,
+    eof: bool,
+}
+
+impl<R: Read> Base64x32Reader<R> {
+    pub fn new(inner: R) -> Self {
+        Base64x32Reader {
+            inner,
+            pending_bytes: Vec::new(),
+            pending_chars: Vec::new(),
+            bit_buf: 0,
+            bit_count: 0,
+            chars_consumed: 0,
+            eof: false,
+        }
+    }
+
+    fn pull_chars(&mut self) -> io::Result<()> {
+        let mut chunk = [0u8; 512];
+        let n = self.inner.read(&mut chunk)?;
+        if n == 0 {
+            self.eof = true;
+        } else {
+            self.pending_bytes.extend_from_slice(&chunk[..n]);
+        }
+
+        let (valid, invalid_tail) = match std::str::from_utf8(&self.pending_bytes) {
+            Ok(s) => (s.len(), 0),
+            Err(e) => (e.valid_up_to(), self.pending_bytes.len() - e.valid_up_to()),
+        };
+
+        if valid > 0 {
+            let text = std::str::from_utf8(&self.pending_bytes[..valid]).unwrap();
+            self.pending_chars.extend(text.chars());
+            self.pending_bytes.drain(..valid);
+        }
+
+        if self.eof && invalid_tail > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "truncated utf-8 sequence at end of stream",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Decodes just enough pending symbols to give `read` a byte to drain,
+    /// rather than draining every pending pair in one shot - `pull_chars`
+    /// can buffer hundreds of symbols at once, and folding all of them into
+    /// `bit_buf` before any byte is extracted would overflow the `u32`
+    /// accumulator.
+    fn decode_pending_symbols(&mut self) -> Result<(), Base64x32Error> {
+        while self.bit_count < 8 && self.pending_chars.len() >= 2 {
+            let symbol: String = self.pending_chars.drain(..2).collect();
+            let val = decode_base(symbol, self.chars_consumed)?;
+            self.chars_consumed += 2;
+            self.bit_buf = (self.bit_buf << 11) | val as u32;
+            self.bit_count += 11;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Base64x32Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+
+        while written < buf.len() {
+            if self.bit_count < 8 {
+                self.decode_pending_symbols().map_err(to_io_error)?;
+            }
+
+            if self.bit_count < 8 {
+                if self.eof {
+                    if self.pending_chars.len() == 1 {
+                        let last = self.pending_chars.remove(0);
+                        let val = decode_b64(last.to_string(), self.chars_consumed).map_err(to_io_error)?;
+                        self.chars_consumed += 1;
+                        self.bit_buf = (self.bit_buf << 6) | val as u32;
+                        self.bit_count += 6;
+                    } else {
+                        break;
+                    }
+                } else {
+                    self.pull_chars()?;
+                    continue;
+                }
+            }
+
+            if self.bit_count < 8 {
+                break;
+            }
+
+            let shift = self.bit_count - 8;
+            buf[written] = ((self.bit_buf >> shift) & 0xFF) as u8;
+            self.bit_count -= 8;
+            self.bit_buf &= (1u32 << self.bit_count) - 1;
+            written += 1;
+        }
+
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn round_trip_many_symbols_does_not_overflow() {
+        // `pull_chars` buffers up to 512 bytes (~256 symbols) at a time, so
+        // this needs to exceed that in a single inner read to exercise the
+        // overflow this test guards against.
+        let input: Vec<u8> = (0..2000u32).map(|i| i as u8).collect();
+
+        let mut writer = Base64x32Writer::new(Vec::new());
+        writer.write_all(&input).unwrap();
+        let encoded = writer.finish().unwrap();
+
+        let mut reader = Base64x32Reader::new(Cursor::new(encoded));
+        let mut decoded = Vec::new();
+        reader.read_to_end(&mut decoded).unwrap();
+
+        assert_eq!(decoded, input);
+    }
+}